@@ -6,9 +6,9 @@
 
 use crate::util::{bail, ensure_kv_empty, ident, path_is_single, KvMap, KvValue};
 use crate::{util, ParseResult};
-use proc_macro2::{Ident, Punct, Span, TokenStream};
+use proc_macro2::{Ident, Punct, Span, TokenStream, TokenTree};
 use quote::spanned::Spanned;
-use quote::{format_ident, quote};
+use quote::{format_ident, quote, ToTokens};
 use venial::{Attribute, NamedField, Struct, StructFields, TyExpr};
 
 pub fn transform(input: TokenStream) -> ParseResult<TokenStream> {
@@ -29,6 +29,7 @@ pub fn transform(input: TokenStream) -> ParseResult<TokenStream> {
 
     let prv = quote! { ::godot::private };
     let deref_impl = make_deref_impl(class_name, &fields);
+    let exported_fields = fields.exported_fields.clone();
 
     let (godot_init_impl, create_fn);
     if struct_cfg.has_generated_init {
@@ -39,7 +40,10 @@ pub fn transform(input: TokenStream) -> ParseResult<TokenStream> {
         create_fn = quote! { None };
     };
 
-    let godot_properties_impl = make_godot_properties_impl(class_name, struct_cfg.properties);
+    let godot_properties_impl =
+        make_godot_properties_impl(class_name, struct_cfg.properties, &exported_fields);
+    let godot_exports_impl = make_godot_exports_impl(class_name, &exported_fields);
+    let godot_signals_impl = make_godot_signals_impl(class_name, struct_cfg.signals);
 
     Ok(quote! {
         impl ::godot::obj::GodotClass for #class_name {
@@ -52,6 +56,8 @@ pub fn transform(input: TokenStream) -> ParseResult<TokenStream> {
 
         #godot_init_impl
         #godot_properties_impl
+        #godot_exports_impl
+        #godot_signals_impl
         #deref_impl
 
         ::godot::sys::plugin_add!(__GODOT_PLUGIN_REGISTRY in #prv; #prv::ClassPlugin {
@@ -108,12 +114,13 @@ fn parse_struct_attributes(class: &Struct) -> ParseResult<ClassAttributes> {
         base_ty: base,
         has_generated_init,
         properties: parse_property_attrs(&class.attributes)?,
+        signals: parse_signal_attrs(&class.attributes)?,
     })
 }
 
 /// Returns field names and 1 base field, if available
 fn parse_fields(class: &Struct) -> ParseResult<Fields> {
-    let mut all_field_names = vec![];
+    let mut all_fields = vec![];
     let mut exported_fields = vec![];
     let mut base_field = Option::<ExportedField>::None;
 
@@ -131,8 +138,9 @@ fn parse_fields(class: &Struct) -> ParseResult<Fields> {
     // Attributes on struct fields
     for (field, _punct) in fields {
         let mut is_base = false;
+        let mut default = None;
 
-        // #[base] or #[export]
+        // #[base], #[export] or #[init(default = ...)]
         for attr in field.attributes.iter() {
             if let Some(path) = attr.get_single_path_segment() {
                 if path == "base" {
@@ -148,19 +156,51 @@ fn parse_fields(class: &Struct) -> ParseResult<Fields> {
                     }
                     base_field = Some(ExportedField::new(&field))
                 } else if path == "export" {
-                    exported_fields.push(ExportedField::new(&field))
+                    // Bare `#[export]` (no parenthesized hint group) must keep working, same as
+                    // `#[base]` above never parses `attr.value` for its own bare form.
+                    let mut value_tokens = TokenStream::new();
+                    attr.value.to_tokens(&mut value_tokens);
+
+                    let (hint, hint_string) = if value_tokens.is_empty() {
+                        default_property_hint()
+                    } else {
+                        let mut map = util::parse_kv_group(&attr.value)?;
+                        let (hint, hint_string) = parse_property_hint(&mut map, attr.__span())?;
+                        ensure_kv_empty(map, attr.__span())?;
+                        (hint, hint_string)
+                    };
+
+                    let mut exported_field = ExportedField::new(&field);
+                    exported_field.hint = hint;
+                    exported_field.hint_string = hint_string;
+                    exported_fields.push(exported_field)
+                } else if path == "init" {
+                    let mut map = util::parse_kv_group(&attr.value)?;
+                    if let Some(value) = map.remove("default") {
+                        match value {
+                            KvValue::Expr(expr) => default = Some(expr),
+                            _ => bail("#[init(default = ...)] expects an expression", attr)?,
+                        }
+                    } else {
+                        bail("#[init] currently only supports the 'default' key", attr)?;
+                    }
+                    ensure_kv_empty(map, attr.__span())?;
                 }
             }
         }
 
         // Exported or Rust-only fields
         if !is_base {
-            all_field_names.push(field.name.clone())
+            all_fields.push(FieldWithDefault {
+                name: field.name.clone(),
+                default,
+            })
         }
     }
 
     Ok(Fields {
-        all_field_names,
+        all_fields,
+        exported_fields,
         base_field,
     })
 }
@@ -191,7 +231,8 @@ fn parse_property_attrs(attributes: &Vec<Attribute>) -> ParseResult<Vec<Property
         let path = &attr.path;
         if path_is_single(path, "property") {
             let property_name: String;
-            let property_variant_type: String;
+            let property_ty: Option<TokenStream>;
+            let property_variant_type: Option<String>;
             let property_getter: String;
             let property_setter: String;
             let mut map = util::parse_kv_group(&attr.value)?;
@@ -210,9 +251,26 @@ fn parse_property_attrs(attributes: &Vec<Attribute>) -> ParseResult<Vec<Property
                     attr,
                 );
             }
+            if let Some(ty) = map.remove("ty") {
+                match ty {
+                    // Bare type name, e.g. `ty = i32`.
+                    KvValue::Ident(ty) => property_ty = Some(quote! { #ty }),
+                    // Anything more than a single identifier, e.g. `ty = Array<GString>` or
+                    // `ty = Option<Gd<Node>>`.
+                    KvValue::Expr(ty) => property_ty = Some(ty),
+                    _ => {
+                        return bail::<Vec<PropertyInfoAttribute>, _>(
+                            "#[property] attribute with a ty that isn't a type",
+                            attr,
+                        )
+                    }
+                }
+            } else {
+                property_ty = None;
+            }
             if let Some(variant_type) = map.remove("variant_type") {
                 if let KvValue::Lit(variant_type) = variant_type {
-                    property_variant_type = variant_type.clone();
+                    property_variant_type = Some(variant_type.clone());
                 } else {
                     return bail::<Vec<PropertyInfoAttribute>, _>(
                         "#[property] attribute with a variant_type that isn't an identifier",
@@ -220,8 +278,12 @@ fn parse_property_attrs(attributes: &Vec<Attribute>) -> ParseResult<Vec<Property
                     );
                 }
             } else {
+                property_variant_type = None;
+            }
+            if property_ty.is_none() && property_variant_type.is_none() {
                 return bail::<Vec<PropertyInfoAttribute>, _>(
-                    "#[property] attribute without any variant_type",
+                    "#[property] attribute needs either 'ty' (to infer the variant type and class \
+                     from the Rust field type) or an explicit 'variant_type'",
                     attr,
                 );
             }
@@ -255,12 +317,16 @@ fn parse_property_attrs(attributes: &Vec<Attribute>) -> ParseResult<Vec<Property
                     attr,
                 );
             }
+            let (hint, hint_string) = parse_property_hint(&mut map, attr.__span())?;
             ensure_kv_empty(map, attr.__span())?;
             property_attributes.push(PropertyInfoAttribute {
                 name: property_name,
                 getter: property_getter,
                 setter: property_setter,
+                ty: property_ty,
                 variant_type: property_variant_type,
+                hint,
+                hint_string,
             });
         }
     }
@@ -274,30 +340,292 @@ struct PropertyInfoAttribute {
     name: String,
     getter: String,
     setter: String,
-    variant_type: String,
+    /// Rust type to infer the `VariantType` and `ClassName` from; takes precedence over
+    /// `variant_type` when present. Arbitrary type expressions (generics, qualified paths) are
+    /// supported, same as the `ty` carried by an `#[export]` field.
+    ty: Option<TokenStream>,
+    /// Manual fallback for when the Rust type isn't known or the inference doesn't apply.
+    variant_type: Option<String>,
+    hint: TokenStream,
+    hint_string: TokenStream,
+}
+
+/// Parses the recognized `PropertyHint` keys (`range`, `enum`, `file` and `multiline`) out of
+/// `map`, returning `(PropertyHint, hint_string)` tokens. Defaults to `PROPERTY_HINT_NONE` with
+/// an empty hint string if none of the keys are present.
+fn parse_property_hint(map: &mut KvMap, span: Span) -> ParseResult<(TokenStream, TokenStream)> {
+    let mut hint = None;
+
+    let mut take_hint = |key: &str, hint_tokens: TokenStream| -> ParseResult<Option<String>> {
+        let Some(value) = map.remove(key) else {
+            return Ok(None);
+        };
+        if hint.is_some() {
+            return bail(
+                "#[export]/#[property] can only have one hint at a time",
+                span,
+            );
+        }
+        let hint_string = match value {
+            KvValue::Lit(lit) => lit.trim_matches('"').to_string(),
+            KvValue::None => String::new(),
+            KvValue::Ident(ident) => ident.to_string(),
+            _ => return bail(&format!("invalid value for hint '{key}'"), span),
+        };
+        hint = Some(hint_tokens);
+        Ok(Some(hint_string))
+    };
+
+    let mut hint_string = String::new();
+    if let Some(range) = take_hint(
+        "range",
+        quote! { ::godot::engine::global::PropertyHint::PROPERTY_HINT_RANGE },
+    )? {
+        let parts: Vec<&str> = range.split(',').map(str::trim).collect();
+        let all_numeric = parts.iter().all(|part| part.parse::<f64>().is_ok());
+        if !(2..=3).contains(&parts.len()) || !all_numeric {
+            return bail(
+                "#[export(range = \"min,max[,step]\")] expects 2-3 comma-separated numbers",
+                span,
+            );
+        }
+        hint_string = range;
+    }
+    if let Some(variants) = take_hint(
+        "enum",
+        quote! { ::godot::engine::global::PropertyHint::PROPERTY_HINT_ENUM },
+    )? {
+        hint_string = variants;
+    }
+    if let Some(filter) = take_hint(
+        "file",
+        quote! { ::godot::engine::global::PropertyHint::PROPERTY_HINT_FILE },
+    )? {
+        hint_string = filter;
+    }
+    if take_hint(
+        "multiline",
+        quote! { ::godot::engine::global::PropertyHint::PROPERTY_HINT_MULTILINE_TEXT },
+    )?
+    .is_some()
+    {
+        hint_string = String::new();
+    }
+
+    match hint {
+        Some(hint) => Ok((
+            hint,
+            quote! { ::godot::builtin::GString::from(#hint_string) },
+        )),
+        None => Ok(default_property_hint()),
+    }
+}
+
+/// Default `(PropertyHint, hint_string)` tokens for a field/property with no hint key present.
+fn default_property_hint() -> (TokenStream, TokenStream) {
+    (
+        quote! { ::godot::engine::global::PropertyHint::PROPERTY_HINT_NONE },
+        quote! { ::godot::builtin::GString::from("") },
+    )
+}
+
+/// Tokens for the `VariantType` of a Rust field type, dispatched through its `Property` impl.
+fn property_variant_type(ty: impl quote::ToTokens) -> TokenStream {
+    quote! {
+        <<#ty as ::godot::bind::property::Property>::Intermediate as ::godot::builtin::meta::VariantMetadata>::variant_type()
+    }
+}
+
+/// Tokens for the `ClassName` of a Rust field type, dispatched through its `Property` impl.
+///
+/// For object-typed fields this is the field's own class, which the editor needs in order to
+/// instantiate the right class for `PROPERTY_USAGE_EDITOR_INSTANTIATE_OBJECT`.
+fn property_variant_class_name(ty: impl quote::ToTokens) -> TokenStream {
+    quote! {
+        <<#ty as ::godot::bind::property::Property>::Intermediate as ::godot::builtin::meta::VariantMetadata>::class_name()
+    }
 }
 
 struct ClassAttributes {
     base_ty: Ident,
     has_generated_init: bool,
     properties: Vec<PropertyInfoAttribute>,
+    signals: Vec<SignalInfo>,
+}
+
+struct SignalArg {
+    name: Ident,
+    ty: TokenStream,
+}
+
+struct SignalInfo {
+    name: String,
+    args: Vec<SignalArg>,
+}
+
+/// Parses struct-level `#[signal(name = "...", args(x: i32, y: Vector2))]` attributes.
+fn parse_signal_attrs(attributes: &Vec<Attribute>) -> ParseResult<Vec<SignalInfo>> {
+    let mut signals = Vec::new();
+    for attr in attributes.iter() {
+        if path_is_single(&attr.path, "signal") {
+            signals.push(parse_signal_attr(attr)?);
+        }
+    }
+    Ok(signals)
+}
+
+fn parse_signal_attr(attr: &Attribute) -> ParseResult<SignalInfo> {
+    use proc_macro2::Delimiter;
+
+    let mut tokens = signal_attr_body(attr)?.into_iter().peekable();
+
+    let mut name = None;
+    let mut args = Vec::new();
+
+    while let Some(token) = tokens.next() {
+        let TokenTree::Ident(key) = token else {
+            return bail("expected a key in #[signal(...)]", attr);
+        };
+
+        if key == "name" {
+            match tokens.next() {
+                Some(TokenTree::Punct(p)) if p.as_char() == '=' => {}
+                _ => return bail("expected '=' after 'name' in #[signal(...)]", attr),
+            }
+            match tokens.next() {
+                Some(TokenTree::Literal(lit)) => {
+                    name = Some(unquote_literal(&lit.to_string()));
+                }
+                _ => return bail("#[signal] 'name' expects a string literal", attr),
+            }
+        } else if key == "args" {
+            match tokens.next() {
+                Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Parenthesis => {
+                    args = parse_signal_args(group.stream(), attr)?;
+                }
+                _ => {
+                    return bail(
+                        "#[signal] 'args' expects a parenthesized list, e.g. args(x: i32)",
+                        attr,
+                    )
+                }
+            }
+        } else {
+            return bail(&format!("unknown key '{key}' in #[signal(...)]"), attr);
+        }
+
+        match tokens.peek() {
+            Some(TokenTree::Punct(p)) if p.as_char() == ',' => {
+                tokens.next();
+            }
+            Some(_) => return bail("expected ',' in #[signal(...)]", attr),
+            None => {}
+        }
+    }
+
+    let Some(name) = name else {
+        return bail("#[signal] attribute without any name", attr);
+    };
+    Ok(SignalInfo { name, args })
+}
+
+/// Extracts the parenthesized contents of a `#[signal(...)]` attribute's token tree.
+fn signal_attr_body(attr: &Attribute) -> ParseResult<TokenStream> {
+    use proc_macro2::Delimiter;
+
+    let mut value_tokens = TokenStream::new();
+    attr.value.to_tokens(&mut value_tokens);
+
+    match value_tokens.into_iter().next() {
+        Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Parenthesis => {
+            Ok(group.stream())
+        }
+        _ => bail("expected #[signal(...)]", attr),
+    }
+}
+
+/// Removes the surrounding quotes from a literal's source text (e.g. `"\"foo\""` -> `foo`).
+fn unquote_literal(lit: &str) -> String {
+    lit.trim_matches('"').to_string()
+}
+
+/// Parses a `x: i32, y: Vector2` signal argument list (the contents of `args(...)`).
+fn parse_signal_args(tokens: TokenStream, attr: &Attribute) -> ParseResult<Vec<SignalArg>> {
+    let mut args = Vec::new();
+    for segment in split_top_level_commas(tokens) {
+        let mut segment = segment.into_iter();
+        let Some(TokenTree::Ident(name)) = segment.next() else {
+            return bail("expected 'name: Type' in #[signal] args", attr);
+        };
+        match segment.next() {
+            Some(TokenTree::Punct(p)) if p.as_char() == ':' => {}
+            _ => return bail("expected ':' after argument name in #[signal] args", attr),
+        }
+        let ty: TokenStream = segment.collect();
+        if ty.is_empty() {
+            return bail("expected a type after ':' in #[signal] args", attr);
+        }
+        args.push(SignalArg { name, ty });
+    }
+    Ok(args)
+}
+
+/// Splits a token stream on its top-level commas. Commas nested inside a `Group` (e.g. `(...)`)
+/// are kept as-is, and so are commas nested inside generic angle brackets (e.g. `HashMap<K, V>`),
+/// which aren't a `Group` at the token level and so need explicit depth tracking.
+fn split_top_level_commas(tokens: TokenStream) -> Vec<TokenStream> {
+    let mut segments = Vec::new();
+    let mut current = Vec::new();
+    let mut angle_depth: u32 = 0;
+    for token in tokens {
+        match &token {
+            TokenTree::Punct(p) if p.as_char() == '<' => {
+                angle_depth += 1;
+                current.push(token);
+            }
+            TokenTree::Punct(p) if p.as_char() == '>' => {
+                angle_depth = angle_depth.saturating_sub(1);
+                current.push(token);
+            }
+            TokenTree::Punct(p) if p.as_char() == ',' && angle_depth == 0 => {
+                segments.push(current.drain(..).collect());
+            }
+            _ => current.push(token),
+        }
+    }
+    if !current.is_empty() {
+        segments.push(current.into_iter().collect());
+    }
+    segments
 }
 
 struct Fields {
-    all_field_names: Vec<Ident>,
+    all_fields: Vec<FieldWithDefault>,
+    exported_fields: Vec<ExportedField>,
     base_field: Option<ExportedField>,
 }
 
+/// A non-base field, plus its optional `#[init(default = ...)]` value.
+struct FieldWithDefault {
+    name: Ident,
+    default: Option<TokenStream>,
+}
+
+#[derive(Clone)]
 struct ExportedField {
     name: Ident,
-    _ty: TyExpr,
+    ty: TyExpr,
+    hint: TokenStream,
+    hint_string: TokenStream,
 }
 
 impl ExportedField {
     fn new(field: &NamedField) -> Self {
         Self {
             name: field.name.clone(),
-            _ty: field.ty.clone(),
+            ty: field.ty.clone(),
+            hint: quote! { ::godot::engine::global::PropertyHint::PROPERTY_HINT_NONE },
+            hint_string: quote! { ::godot::builtin::GString::new() },
         }
     }
 }
@@ -309,8 +637,12 @@ fn make_godot_init_impl(class_name: &Ident, fields: Fields) -> TokenStream {
         TokenStream::new()
     };
 
-    let rest_init = fields.all_field_names.into_iter().map(|field| {
-        quote! { #field: std::default::Default::default(), }
+    let rest_init = fields.all_fields.into_iter().map(|field| {
+        let name = field.name;
+        match field.default {
+            Some(default) => quote! { #name: #default, },
+            None => quote! { #name: std::default::Default::default(), },
+        }
     });
 
     quote! {
@@ -325,26 +657,56 @@ fn make_godot_init_impl(class_name: &Ident, fields: Fields) -> TokenStream {
     }
 }
 
+/// Name of the generated getter method for an `#[export]` field.
+fn export_getter_name(field_name: &Ident) -> Ident {
+    format_ident!("__godot_export_get_{}", field_name)
+}
+
+/// Name of the generated setter method for an `#[export]` field.
+fn export_setter_name(field_name: &Ident) -> Ident {
+    format_ident!("__godot_export_set_{}", field_name)
+}
+
 fn make_godot_properties_impl(
     class_name: &Ident,
     properties: Vec<PropertyInfoAttribute>,
+    exported_fields: &[ExportedField],
 ) -> TokenStream {
-    let property_info_tokens: Vec<TokenStream> = properties
+    let mut property_info_tokens: Vec<TokenStream> = properties
         .into_iter()
         .map(|property_info: PropertyInfoAttribute| -> TokenStream {
             use std::str::FromStr;
             let name = proc_macro2::Literal::from_str(&property_info.name).unwrap();
             let getter = proc_macro2::Literal::from_str(&property_info.getter).unwrap();
             let setter = proc_macro2::Literal::from_str(&property_info.setter).unwrap();
-            let variant_type = property_info.variant_type;
+            let (variant_type_tokens, variant_class_name_tokens) = match &property_info.ty {
+                Some(ty) => (property_variant_type(ty), property_variant_class_name(ty)),
+                None => {
+                    let variant_type = format_ident!(
+                        "{}",
+                        property_info
+                            .variant_type
+                            .as_deref()
+                            .expect("checked in parse_property_attrs")
+                            .trim_matches('"')
+                    );
+                    (
+                        quote! { ::godot_ffi::VariantType::#variant_type },
+                        quote! { ::godot_core::builtin::meta::ClassName::new::<#class_name>() },
+                    )
+                }
+            };
+            let hint_tokens = property_info.hint;
+            let hint_string_tokens = property_info.hint_string;
             quote! {
                 let class_name = StringName::from(#class_name::CLASS_NAME);
-                let property_info = PropertyInfo::new(
-                    //#variant_type,
-                    ::godot_ffi::VariantType::Int,
-                    ::godot_core::builtin::meta::ClassName::new::<#class_name>(),
+                let mut property_info = PropertyInfo::new(
+                    #variant_type_tokens,
+                    #variant_class_name_tokens,
                     StringName::from(#name),
                 );
+                property_info.hint = #hint_tokens;
+                property_info.hint_string = #hint_string_tokens;
                 let property_info_sys = property_info.property_sys();
 
                 let getter_string_name = StringName::from(#getter);
@@ -359,6 +721,41 @@ fn make_godot_properties_impl(
             }
         })
         .collect();
+
+    property_info_tokens.extend(exported_fields.iter().map(|field| -> TokenStream {
+        let field_name = &field.name;
+        let field_name_str = field_name.to_string();
+        let getter = export_getter_name(field_name);
+        let setter = export_setter_name(field_name);
+        let variant_type_tokens = property_variant_type(&field.ty);
+        let variant_class_name_tokens = property_variant_class_name(&field.ty);
+        let hint_tokens = &field.hint;
+        let hint_string_tokens = &field.hint_string;
+        quote! {
+            let class_name = StringName::from(#class_name::CLASS_NAME);
+            let mut property_info = PropertyInfo::new(
+                #variant_type_tokens,
+                #variant_class_name_tokens,
+                StringName::from(#field_name_str),
+            );
+            property_info.usage = ::godot::sys::GDEXTENSION_PROPERTY_USAGE_DEFAULT
+                | ::godot::sys::GDEXTENSION_PROPERTY_USAGE_EDITOR;
+            property_info.hint = #hint_tokens;
+            property_info.hint_string = #hint_string_tokens;
+            let property_info_sys = property_info.property_sys();
+
+            let getter_string_name = StringName::from(stringify!(#getter));
+            let setter_string_name = StringName::from(stringify!(#setter));
+            godot::sys::interface_fn!(classdb_register_extension_class_property)(
+                godot::sys::get_library(),
+                class_name.string_sys(),
+                std::ptr::addr_of!(property_info_sys),
+                setter_string_name.string_sys(),
+                getter_string_name.string_sys(),
+            );
+        }
+    }));
+
     quote! {
         impl ::godot::obj::cap::GodotProperties for #class_name {
 
@@ -375,6 +772,92 @@ fn make_godot_properties_impl(
     }
 }
 
+/// Generates a `#[func]`-annotated getter/setter pair for each `#[export]` field, so that
+/// `make_godot_properties_impl`'s property registration has methods to point the editor at.
+fn make_godot_exports_impl(class_name: &Ident, exported_fields: &[ExportedField]) -> TokenStream {
+    if exported_fields.is_empty() {
+        return TokenStream::new();
+    }
+
+    let accessors = exported_fields.iter().map(|field| {
+        let field_name = &field.name;
+        let field_ty = &field.ty;
+        let getter = export_getter_name(field_name);
+        let setter = export_setter_name(field_name);
+
+        quote! {
+            #[func]
+            fn #getter(&self) -> #field_ty {
+                self.#field_name.clone()
+            }
+
+            #[func]
+            fn #setter(&mut self, value: #field_ty) {
+                self.#field_name = value;
+            }
+        }
+    });
+
+    quote! {
+        #[::godot::bind::godot_api]
+        impl #class_name {
+            #( #accessors )*
+        }
+    }
+}
+
+/// Registers each `#[signal]` with Godot, along with the `PropertyInfo` for its arguments.
+fn make_godot_signals_impl(class_name: &Ident, signals: Vec<SignalInfo>) -> TokenStream {
+    if signals.is_empty() {
+        return TokenStream::new();
+    }
+
+    let signal_registrations = signals.into_iter().map(|signal| {
+        let signal_name_str = &signal.name;
+
+        let arg_infos = signal.args.iter().map(|arg| {
+            let arg_name = arg.name.to_string();
+            let variant_type_tokens = property_variant_type(&arg.ty);
+            let variant_class_name_tokens = property_variant_class_name(&arg.ty);
+            quote! {
+                PropertyInfo::new(
+                    #variant_type_tokens,
+                    #variant_class_name_tokens,
+                    StringName::from(#arg_name),
+                )
+                .property_sys()
+            }
+        });
+
+        quote! {
+            let class_name = StringName::from(#class_name::CLASS_NAME);
+            let signal_name = StringName::from(#signal_name_str);
+            let arg_infos = [ #(#arg_infos),* ];
+            godot::sys::interface_fn!(classdb_register_extension_class_signal)(
+                godot::sys::get_library(),
+                class_name.string_sys(),
+                signal_name.string_sys(),
+                arg_infos.as_ptr(),
+                arg_infos.len() as i32,
+            );
+        }
+    });
+
+    quote! {
+        impl ::godot::obj::cap::GodotSignals for #class_name {
+            fn __register_signals() {
+                unsafe {
+                    #(
+                        {
+                            #signal_registrations
+                        }
+                    )*
+                }
+            }
+        }
+    }
+}
+
 fn make_deref_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
     let base_field = if let Some(ExportedField { name, .. }) = &fields.base_field {
         name
@@ -20,10 +20,12 @@ mod util;
         property,
         export,
         base,
+        init,
         signal,
         getter,
         setter,
         name,
+        ty,
         variant_type
     )
 )]